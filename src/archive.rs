@@ -1,10 +1,15 @@
+use crate::models::extended::LeaderboardEntry;
+use anyhow::Context;
+use chrono::{DateTime, NaiveDateTime};
+use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
+use serde::Deserialize;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{Seek, Write};
 use std::iter::Iterator;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::{fs, io};
 use walkdir::{DirEntry, WalkDir};
 use zip::write::SimpleFileOptions;
@@ -38,26 +43,58 @@ impl ArchiveMode {
                 let walkdir = WalkDir::new(src);
                 let it = walkdir.into_iter();
 
-                zip_dir(&mut it.filter_map(|e| e.ok()), src, file)?;
+                zip_dir(
+                    &mut it.filter_map(|e| e.ok()).filter(|e| !is_partial(e)),
+                    src,
+                    file,
+                )?;
                 Ok(Some(to))
             }
             ArchiveMode::Skip => Ok(None),
         }
     }
+
+    /// Extract the tar.gz or zip file `from` into the directory `to` and
+    /// return the paths of the extracted files, the counterpart of
+    /// [`ArchiveMode::make_archive`].
+    ///
+    /// Entries that would end up outside of `to`, like `../file` or absolute
+    /// paths, fail the extraction before anything of them is written.
+    pub fn extract(
+        &self,
+        from: impl AsRef<Path>,
+        to: impl AsRef<Path>,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        match self {
+            ArchiveMode::Tar => extract_tarball(File::open(from)?, to),
+            ArchiveMode::Zip => extract_zip(File::open(from)?, to),
+            ArchiveMode::Skip => Ok(Vec::new()),
+        }
+    }
 }
 
 /// unzip file into location of `to`
 pub fn unzip(file: impl AsRef<Path>, to: impl AsRef<Path>) -> anyhow::Result<()> {
-    let file = file.as_ref();
+    ArchiveMode::Zip.extract(file, to)?;
+    Ok(())
+}
+
+fn extract_zip<R: Read + Seek>(reader: R, to: impl AsRef<Path>) -> anyhow::Result<Vec<PathBuf>> {
     let to = to.as_ref();
-    let file = fs::File::open(file)?;
-    let mut archive = zip::ZipArchive::new(file)?;
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let mut extracted = Vec::with_capacity(archive.len());
 
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)?;
-        let outpath = to.join(file.mangled_name());
+        let outpath = match file.enclosed_name() {
+            Some(name) => to.join(name),
+            None => anyhow::bail!(
+                "Archive entry `{}` escapes the target directory",
+                file.name()
+            ),
+        };
 
-        if file.name().ends_with('/') {
+        if file.is_dir() {
             fs::create_dir_all(&outpath)?;
         } else {
             if let Some(p) = outpath.parent() {
@@ -67,6 +104,7 @@ pub fn unzip(file: impl AsRef<Path>, to: impl AsRef<Path>) -> anyhow::Result<()>
             }
             let mut outfile = fs::File::create(&outpath)?;
             io::copy(&mut file, &mut outfile)?;
+            extracted.push(outpath.clone());
         }
 
         // Get and Set permissions
@@ -80,9 +118,112 @@ pub fn unzip(file: impl AsRef<Path>, to: impl AsRef<Path>) -> anyhow::Result<()>
         }
     }
 
+    Ok(extracted)
+}
+
+fn extract_tarball<R: Read>(reader: R, to: impl AsRef<Path>) -> anyhow::Result<Vec<PathBuf>> {
+    let to = to.as_ref();
+    fs::create_dir_all(to)?;
+    let mut tar = tar::Archive::new(GzDecoder::new(reader));
+    let mut extracted = Vec::new();
+
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        let name = entry.path()?.into_owned();
+        if name
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+        {
+            anyhow::bail!(
+                "Archive entry `{}` escapes the target directory",
+                name.display()
+            );
+        }
+        let is_file = entry.header().entry_type().is_file();
+        entry.unpack_in(to)?;
+        if is_file {
+            extracted.push(to.join(name.components().as_path()));
+        }
+    }
+
+    Ok(extracted)
+}
+
+/// A row of the csv inside a downloaded leaderboard zip.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LeaderboardRow {
+    team_id: i64,
+    team_name: String,
+    submission_date: String,
+    score: String,
+}
+
+/// Read the entries of the leaderboard csv inside the zip `file`, as
+/// downloaded by `KaggleApiClient::competition_download_leaderboard`.
+pub fn read_leaderboard_zip(file: impl AsRef<Path>) -> anyhow::Result<Vec<LeaderboardEntry>> {
+    let file = file.as_ref();
+    let mut archive = zip::ZipArchive::new(File::open(file)?)?;
+    let csv = archive
+        .by_index(0)
+        .with_context(|| format!("Empty leaderboard archive {}", file.display()))?;
+
+    let mut entries = Vec::new();
+    for row in csv::Reader::from_reader(csv).deserialize() {
+        let row: LeaderboardRow = row?;
+        let submission_date =
+            NaiveDateTime::parse_from_str(&row.submission_date, "%Y-%m-%d %H:%M:%S%.f")
+                .or_else(|_| {
+                    DateTime::parse_from_rfc3339(&row.submission_date).map(|d| d.naive_utc())
+                })
+                .with_context(|| format!("Invalid submission date {}", row.submission_date))?;
+        entries.push(LeaderboardEntry {
+            team_id: row.team_id,
+            team_name: row.team_name,
+            submission_date,
+            score: row.score,
+        });
+    }
+    Ok(entries)
+}
+
+/// Whether the file starts with the magic bytes of a gzip, zip or 7z archive.
+pub fn is_compressed(file: impl AsRef<Path>) -> io::Result<bool> {
+    let mut magic = Vec::with_capacity(6);
+    File::open(file)?.take(6).read_to_end(&mut magic)?;
+    Ok(magic.starts_with(&[0x1f, 0x8b])
+        || magic.starts_with(b"PK\x03\x04")
+        || magic.starts_with(&[b'7', b'z', 0xbc, 0xaf, 0x27, 0x1c]))
+}
+
+/// Whether the file starts with the magic bytes of a zip archive.
+pub fn is_zip(file: impl AsRef<Path>) -> io::Result<bool> {
+    let mut magic = Vec::with_capacity(4);
+    File::open(file)?.take(4).read_to_end(&mut magic)?;
+    Ok(is_zip_magic(&magic))
+}
+
+/// Whether `head`, the first bytes of a file, are the magic bytes of a zip
+/// archive.
+pub fn is_zip_magic(head: &[u8]) -> bool {
+    // an empty archive consists of the end of central directory record only
+    head.starts_with(b"PK\x03\x04") || head.starts_with(b"PK\x05\x06")
+}
+
+/// gzip compress `file` into `to`
+pub fn gzip(file: impl AsRef<Path>, to: impl AsRef<Path>) -> anyhow::Result<()> {
+    let mut src = File::open(file)?;
+    let mut enc = GzEncoder::new(File::create(to)?, Compression::default());
+    io::copy(&mut src, &mut enc)?;
+    enc.finish()?;
     Ok(())
 }
 
+/// Whether the entry is an in progress download, which is not archived.
+fn is_partial(entry: &DirEntry) -> bool {
+    entry.file_type().is_file() && crate::KaggleApiClient::is_partial_path(entry.path())
+}
+
 fn zip_dir<T>(
     it: &mut impl Iterator<Item = DirEntry>,
     prefix: impl AsRef<Path>,
@@ -95,7 +236,6 @@ where
     let mut zip = zip::ZipWriter::new(writer);
     let options = SimpleFileOptions::default().unix_permissions(0o755);
 
-    let mut buffer = Vec::new();
     for entry in it {
         let path = entry.path();
         let name = path.strip_prefix(prefix)?;
@@ -104,11 +244,7 @@ where
         // Some unzip tools unzip files with directory paths correctly, some do not!
         if path.is_file() {
             zip.start_file_from_path(name, options)?;
-            let mut f = File::open(path)?;
-
-            f.read_to_end(&mut buffer)?;
-            zip.write_all(&buffer)?;
-            buffer.clear();
+            io::copy(&mut File::open(path)?, &mut zip)?;
         } else if !name.as_os_str().is_empty() {
             // Only if not root! Avoids path spec / warning
             // and mapname conversion failed error on unzip
@@ -120,8 +256,140 @@ where
 }
 
 fn make_tarball<T: Write>(src: impl AsRef<Path>, writer: T) -> anyhow::Result<()> {
+    let src = src.as_ref();
     let enc = GzEncoder::new(writer, Compression::default());
     let mut tar = tar::Builder::new(enc);
-    tar.append_dir_all(".", src)?;
+    for entry in WalkDir::new(src).min_depth(1).sort_by_file_name() {
+        let entry = entry?;
+        if is_partial(&entry) {
+            continue;
+        }
+        let name = entry.path().strip_prefix(src)?;
+        if entry.file_type().is_dir() {
+            tar.append_dir(name, entry.path())?;
+        } else {
+            tar.append_path_with_name(entry.path(), name)?;
+        }
+    }
+    // finish both layers explicitly, dropping them would swallow write errors
+    tar.into_inner()?.finish()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempdir::TempDir;
+
+    fn fixture(dir: &Path) -> PathBuf {
+        let src = dir.join("src");
+        fs::create_dir_all(src.join("nested")).unwrap();
+        fs::write(src.join("train.csv"), "id\n1\n").unwrap();
+        fs::write(src.join("nested/test.csv"), "id\n2\n").unwrap();
+        fs::write(src.join("nested/large.csv.part"), "id\n").unwrap();
+        src
+    }
+
+    fn extract_round_trip(mode: ArchiveMode) {
+        let dir = TempDir::new("kaggle-archive").unwrap();
+        let src = fixture(dir.path());
+        let archive = mode
+            .make_archive(&src, dir.path().join("data"))
+            .unwrap()
+            .unwrap();
+
+        let out = dir.path().join("out");
+        let mut files = mode.extract(&archive, &out).unwrap();
+        files.sort();
+        assert_eq!(files, [out.join("nested/test.csv"), out.join("train.csv")]);
+        assert_eq!(fs::read_to_string(&files[0]).unwrap(), "id\n2\n");
+        assert_eq!(fs::read_to_string(&files[1]).unwrap(), "id\n1\n");
+    }
+
+    #[test]
+    fn extract_zip_archive() {
+        extract_round_trip(ArchiveMode::Zip);
+    }
+
+    #[test]
+    fn extract_tar_archive() {
+        extract_round_trip(ArchiveMode::Tar);
+    }
+
+    #[test]
+    fn zip_archive_entries() {
+        let dir = TempDir::new("kaggle-archive").unwrap();
+        let src = fixture(dir.path());
+        let archive = ArchiveMode::Zip
+            .make_archive(&src, dir.path().join("data"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(archive, dir.path().join("data.zip"));
+
+        let mut zip = zip::ZipArchive::new(File::open(&archive).unwrap()).unwrap();
+        let mut names: Vec<_> = zip.file_names().map(str::to_string).collect();
+        names.sort();
+        assert_eq!(names, ["nested/", "nested/test.csv", "train.csv"]);
+
+        let mut content = String::new();
+        zip.by_name("nested/test.csv")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "id\n2\n");
+        assert_eq!(
+            ArchiveMode::Skip.make_archive(&src, dir.path()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn tar_archive_entries() {
+        let dir = TempDir::new("kaggle-archive").unwrap();
+        let src = fixture(dir.path());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(src.join("train.csv"), fs::Permissions::from_mode(0o640))
+                .unwrap();
+        }
+        let archive = ArchiveMode::Tar
+            .make_archive(&src, dir.path().join("data"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(archive, dir.path().join("data.tar.gz"));
+
+        let mut tar = tar::Archive::new(GzDecoder::new(File::open(&archive).unwrap()));
+        let mut entries: Vec<_> = tar
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                let name = entry.path().unwrap().to_string_lossy().into_owned();
+                (name, entry.header().mode().unwrap() & 0o777)
+            })
+            .filter(|(name, _)| name.ends_with(".csv"))
+            .collect();
+        entries.sort();
+        assert_eq!(entries[0].0, "nested/test.csv");
+        assert_eq!(entries[1].0, "train.csv");
+        #[cfg(unix)]
+        assert_eq!(entries[1].1, 0o640);
+    }
+
+    #[test]
+    fn reject_zip_slip() {
+        let dir = TempDir::new("kaggle-archive").unwrap();
+        let archive = dir.path().join("evil.zip");
+        let mut zip = zip::ZipWriter::new(File::create(&archive).unwrap());
+        zip.start_file("../evil.txt", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"evil").unwrap();
+        zip.finish().unwrap();
+
+        let out = dir.path().join("out");
+        let err = ArchiveMode::Zip.extract(&archive, &out).unwrap_err();
+        assert!(err.to_string().contains("escapes the target directory"));
+        assert!(!dir.path().join("evil.txt").exists());
+    }
+}
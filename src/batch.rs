@@ -0,0 +1,363 @@
+//! Download many competition and dataset files at once.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use log::debug;
+
+use crate::client::{KaggleApiClient, RetryPolicy};
+
+/// A single file to download with a [`BatchDownloader`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadTask {
+    /// A data file of a competition, stored as `<file-name>`, or as
+    /// `<file-name>.zip` if kaggle sends it zipped.
+    CompetitionFile {
+        competition: String,
+        file_name: String,
+    },
+    /// A file of the newest version of a dataset.
+    DatasetFile { dataset: String, file_name: String },
+}
+
+impl DownloadTask {
+    pub fn competition_file(competition: impl ToString, file_name: impl ToString) -> Self {
+        DownloadTask::CompetitionFile {
+            competition: competition.to_string(),
+            file_name: file_name.to_string(),
+        }
+    }
+
+    pub fn dataset_file(dataset: impl ToString, file_name: impl ToString) -> Self {
+        DownloadTask::DatasetFile {
+            dataset: dataset.to_string(),
+            file_name: file_name.to_string(),
+        }
+    }
+}
+
+/// Downloads a list of [`DownloadTask`]s with at most `max_in_flight`
+/// downloads at a time and no more than `requests_per_second` requests.
+///
+/// Tasks that fail with a `429` or `5xx` response, or a connection error, are
+/// retried on their own, every retry counts against the request limit. The
+/// retries configured on the client don't apply to the tasks, so a rejected
+/// request is never retried twice over.
+///
+/// Example
+///
+/// ```no_run
+/// use kaggle::batch::{BatchDownloader, DownloadTask};
+/// use kaggle::KaggleApiClient;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let kaggle = KaggleApiClient::builder().build()?;
+///     let results = BatchDownloader::new(&kaggle)
+///         .max_in_flight(4)
+///         .requests_per_second(2)
+///         .download(vec![
+///             DownloadTask::competition_file("titanic", "train.csv"),
+///             DownloadTask::dataset_file("unanimad/dataisbeautiful", "r_dataisbeautiful_posts.csv"),
+///         ])
+///         .await;
+///     for result in results {
+///         println!("{}", result?.display());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct BatchDownloader<'a> {
+    client: &'a KaggleApiClient,
+    /// How many downloads run concurrently, defaults to 3
+    max_in_flight: usize,
+    /// Upper limit of requests started per second, if any
+    requests_per_second: Option<u32>,
+    /// How often a failed task is retried, defaults to 3
+    max_retries: u32,
+    /// The delay before the first retry, which grows with every attempt
+    retry_delay: Duration,
+    /// The directory to store the files, otherwise the default locations
+    target: Option<PathBuf>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl<'a> BatchDownloader<'a> {
+    pub fn new(client: &'a KaggleApiClient) -> Self {
+        Self {
+            client,
+            max_in_flight: 3,
+            requests_per_second: None,
+            max_retries: 3,
+            retry_delay: Duration::from_millis(500),
+            target: None,
+            clock: Arc::new(TokioClock),
+        }
+    }
+
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = max_in_flight.max(1);
+        self
+    }
+
+    pub fn requests_per_second(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second = Some(requests_per_second.max(1));
+        self
+    }
+
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    pub fn target(mut self, target: impl Into<PathBuf>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    #[cfg(test)]
+    fn clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Execute all `tasks` and return the location of each downloaded file,
+    /// or the error of its last attempt, in the order of `tasks`.
+    pub async fn download(&self, tasks: Vec<DownloadTask>) -> Vec<anyhow::Result<PathBuf>> {
+        let limiter = RateLimiter::new(self.requests_per_second, self.clock.clone());
+        let client = self.client.without_retries();
+        stream::iter(tasks)
+            .map(|task| {
+                let (client, limiter) = (&client, &limiter);
+                async move { self.run(client, &task, limiter).await }
+            })
+            .buffered(self.max_in_flight)
+            .collect()
+            .await
+    }
+
+    async fn run(
+        &self,
+        client: &KaggleApiClient,
+        task: &DownloadTask,
+        limiter: &RateLimiter,
+    ) -> anyhow::Result<PathBuf> {
+        let policy = RetryPolicy {
+            max_retries: self.max_retries,
+            base_backoff: self.retry_delay,
+            ..self.client.retry_policy()
+        };
+        policy
+            .run(|| async move {
+                limiter.acquire().await;
+                debug!("Downloading {:?}", task);
+                self.execute(client, task).await
+            })
+            .await
+    }
+
+    async fn execute(
+        &self,
+        client: &KaggleApiClient,
+        task: &DownloadTask,
+    ) -> anyhow::Result<PathBuf> {
+        if let Some(target) = &self.target {
+            fs::create_dir_all(target)?;
+        }
+        match task {
+            DownloadTask::CompetitionFile {
+                competition,
+                file_name,
+            } => client
+                .competitions_data_download_file(competition, file_name, self.target.clone())
+                .await
+                .map(|download| download.path),
+            DownloadTask::DatasetFile { dataset, file_name } => client
+                .dataset_download_file(dataset, file_name, self.target.clone(), None)
+                .await
+                .map(|download| download.path),
+        }
+    }
+}
+
+/// The time source of the [`RateLimiter`], replaced in tests.
+#[async_trait]
+trait Clock: fmt::Debug + Send + Sync {
+    fn now(&self) -> Instant;
+
+    async fn sleep_until(&self, deadline: Instant);
+}
+
+#[derive(Debug)]
+struct TokioClock;
+
+#[async_trait]
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    async fn sleep_until(&self, deadline: Instant) {
+        tokio::time::sleep_until(deadline.into()).await
+    }
+}
+
+/// Spaces requests evenly, so that no more than `requests_per_second` start
+/// within any second.
+struct RateLimiter {
+    interval: Option<Duration>,
+    /// The earliest time the next request may start
+    next: Mutex<Option<Instant>>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: Option<u32>, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self {
+            interval: requests_per_second.map(|rps| Duration::from_secs(1) / rps),
+            next: Mutex::new(None),
+            clock,
+        }
+    }
+
+    /// Wait until the next request may start.
+    async fn acquire(&self) {
+        let interval = match self.interval {
+            Some(interval) => interval,
+            None => return,
+        };
+        let start = {
+            let now = self.clock.now();
+            let mut next = self.next.lock().unwrap();
+            let start = next.map_or(now, |next| next.max(now));
+            *next = Some(start + interval);
+            start
+        };
+        self.clock.sleep_until(start).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Authentication;
+    use tempdir::TempDir;
+
+    /// A clock that only advances when slept on and records the time every
+    /// request was released at.
+    #[derive(Debug)]
+    struct MockClock {
+        start: Instant,
+        now: Mutex<Instant>,
+        released: Mutex<Vec<Duration>>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            let start = Instant::now();
+            Self {
+                start,
+                now: Mutex::new(start),
+                released: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+
+        async fn sleep_until(&self, deadline: Instant) {
+            let mut now = self.now.lock().unwrap();
+            *now = (*now).max(deadline);
+            self.released
+                .lock()
+                .unwrap()
+                .push(deadline.duration_since(self.start));
+        }
+    }
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn download_is_send() {
+        let kaggle = KaggleApiClient::builder()
+            .auth(Authentication::with_credentials("name", "key"))
+            .build()
+            .unwrap();
+        let downloader = BatchDownloader::new(&kaggle);
+        // only compiles if the batch can be spawned on a multi threaded runtime
+        assert_send(&downloader.download(Vec::new()));
+    }
+
+    #[tokio::test]
+    async fn batch_download_respects_rate_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let throttled = server
+            .mock(
+                "GET",
+                "/api/v1/competitions/data/download/titanic/file2.csv",
+            )
+            .with_status(429)
+            .expect(1)
+            .create_async()
+            .await;
+        let files = server
+            .mock(
+                "GET",
+                mockito::Matcher::Regex(
+                    r"^/api/v1/competitions/data/download/titanic/file\d\.csv$".to_string(),
+                ),
+            )
+            .with_body("data")
+            .expect(6)
+            .create_async()
+            .await;
+        let kaggle = KaggleApiClient::builder()
+            .base_url(format!("{}/api/v1/", server.url()).parse().unwrap())
+            .auth(Authentication::with_credentials("name", "key"))
+            // the batch retries on its own, through the rate limit
+            .max_retries(3)
+            .base_backoff(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let dir = TempDir::new("kaggle-batch").unwrap();
+        let clock = Arc::new(MockClock::new());
+
+        let tasks = (0..6)
+            .map(|i| DownloadTask::competition_file("titanic", format!("file{}.csv", i)))
+            .collect();
+        let results = BatchDownloader::new(&kaggle)
+            .max_in_flight(3)
+            .requests_per_second(2)
+            .retry_delay(Duration::from_millis(10))
+            .target(dir.path())
+            .clock(clock.clone())
+            .download(tasks)
+            .await;
+
+        let paths: Vec<_> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(paths[2], dir.path().join("file2.csv"));
+        throttled.assert_async().await;
+        files.assert_async().await;
+
+        let mut released = clock.released.lock().unwrap().clone();
+        released.sort();
+        assert_eq!(released.len(), 7);
+        for window in released.windows(3) {
+            assert!(window[2] - window[0] >= Duration::from_secs(1));
+        }
+    }
+}
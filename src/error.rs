@@ -1,12 +1,24 @@
 use crate::models::Error;
 use std::fmt;
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Describes API errors
 #[derive(Debug)]
 pub enum ApiError {
     Unauthorized,
+    /// The credentials are valid but lack the permissions for the request,
+    /// with the server's explanation if it sent one.
+    Forbidden(Option<Error>),
+    /// The requested dataset, competition, kernel or file does not exist,
+    /// with the server's explanation if it sent one.
+    NotFound(Option<Error>),
+    /// Too many requests, with the delay the server asked to wait before
+    /// the next one.
+    RateLimited {
+        retry_after: Option<Duration>,
+    },
     Other(u16),
     ServerError(Error),
 }
@@ -17,6 +29,31 @@ impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ApiError::Unauthorized => write!(f, "Unauthorized request to API"),
+            ApiError::Forbidden(err) => {
+                write!(
+                    f,
+                    "Forbidden request to API, the credentials are valid but lack the required \
+                     permissions (e.g. private competition or competition rules not accepted)"
+                )?;
+                if let Some(err) = err {
+                    write!(f, ": {}", err.message())?;
+                }
+                Ok(())
+            }
+            ApiError::NotFound(err) => {
+                write!(f, "Requested resource was not found")?;
+                if let Some(err) = err {
+                    write!(f, ": {}", err.message())?;
+                }
+                Ok(())
+            }
+            ApiError::RateLimited { retry_after } => {
+                write!(f, "Too many requests to API")?;
+                if let Some(retry_after) = retry_after {
+                    write!(f, ", retry after {} seconds", retry_after.as_secs())?;
+                }
+                Ok(())
+            }
             ApiError::Other(s) => write!(f, "Kaggle API reported error code {}", s),
             ApiError::ServerError(err) => err.fmt(f),
         }
@@ -27,8 +64,22 @@ impl fmt::Display for ApiError {
 pub enum KaggleError {
     #[error("File not found {0}")]
     FileNotFound(PathBuf),
+    #[error("File `{}` not found in dataset `{}`", file_name, dataset)]
+    DatasetFileNotFound { dataset: String, file_name: String },
+    #[error("Invalid file name `{0}`")]
+    InvalidFileName(String),
     #[error("Metadata error: {}", msg)]
     Metadata { msg: String },
+    #[error("Invalid query: {}", msg)]
+    Query { msg: String },
+    #[error("Invalid submission: {}", msg)]
+    Submission { msg: String },
+    #[error("No download directory configured, an explicit target is required")]
+    MissingDownloadDir,
+    #[error("Response body exceeds the limit of {} bytes", limit)]
+    ResponseTooLarge { limit: u64 },
+    #[error("Unexpected response: {}", msg)]
+    UnexpectedResponse { msg: String },
     #[error(transparent)]
     Api {
         #[from]
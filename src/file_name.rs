@@ -0,0 +1,132 @@
+//! Safe local names for files whose name was chosen by the server.
+
+use crate::error::KaggleError;
+
+/// How names of downloaded files, like the files of a kernel's output, are
+/// turned into names that are valid on every platform and stay within the
+/// target directory.
+///
+/// Characters that windows rejects, including both path separators and
+/// control characters, are replaced, trailing dots and spaces are removed
+/// and names longer than `max_len` bytes are shortened, keeping the
+/// extension. Names that end up empty, like `..`, are rejected.
+///
+/// Example
+///
+/// ```
+/// use kaggle::file_name::FileNamePolicy;
+///
+/// let policy = FileNamePolicy::default().replacement('-');
+/// assert_eq!(policy.sanitize("12:30 <run>.csv").unwrap(), "12-30 -run-.csv");
+/// assert!(policy.sanitize("..").is_err());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FileNamePolicy {
+    /// The character that replaces invalid characters, defaults to `_`
+    replacement: char,
+    /// The maximum length of a name in bytes, defaults to 255
+    max_len: usize,
+}
+
+impl FileNamePolicy {
+    /// Characters that are not allowed in windows file names.
+    const INVALID: &'static [char] = &['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+    /// Use `replacement` for invalid characters, an invalid `replacement`
+    /// is ignored.
+    pub fn replacement(mut self, replacement: char) -> Self {
+        if !Self::is_invalid(replacement) {
+            self.replacement = replacement;
+        }
+        self
+    }
+
+    /// Shorten names to at most `max_len` bytes.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len.max(1);
+        self
+    }
+
+    fn is_invalid(c: char) -> bool {
+        c.is_control() || Self::INVALID.contains(&c)
+    }
+
+    /// The safe version of `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KaggleError::InvalidFileName`] if nothing of `name`
+    /// remains, like for `.` or `..`.
+    pub fn sanitize(&self, name: &str) -> Result<String, KaggleError> {
+        let replaced: String = name
+            .chars()
+            .map(|c| {
+                if Self::is_invalid(c) {
+                    self.replacement
+                } else {
+                    c
+                }
+            })
+            .collect();
+        let trimmed = replaced.trim_end_matches(['.', ' ']);
+        if trimmed.is_empty() {
+            return Err(KaggleError::InvalidFileName(name.to_string()));
+        }
+        if trimmed.len() <= self.max_len {
+            return Ok(trimmed.to_string());
+        }
+
+        // keep short extensions, so the file still opens with the right program
+        let ext = match trimmed.rfind('.') {
+            Some(dot) if dot > 0 && trimmed.len() - dot <= self.max_len / 2 => &trimmed[dot..],
+            _ => "",
+        };
+        let mut stem_len = self.max_len - ext.len();
+        while !trimmed.is_char_boundary(stem_len) {
+            stem_len -= 1;
+        }
+        Ok(format!("{}{}", &trimmed[..stem_len], ext))
+    }
+}
+
+impl Default for FileNamePolicy {
+    fn default() -> Self {
+        Self {
+            replacement: '_',
+            max_len: 255,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_windows_invalid_chars() {
+        let policy = FileNamePolicy::default();
+        assert_eq!(
+            policy
+                .sanitize("submission 2020-04-02T12:00:00.csv")
+                .unwrap(),
+            "submission 2020-04-02T12_00_00.csv"
+        );
+        assert_eq!(policy.sanitize("a\\b/c\u{7}?.txt").unwrap(), "a_b_c__.txt");
+        assert_eq!(policy.sanitize("../secret").unwrap(), ".._secret");
+        assert_eq!(policy.sanitize("trailing. ").unwrap(), "trailing");
+        assert!(policy.sanitize("..").is_err());
+        assert!(policy.sanitize("").is_err());
+    }
+
+    #[test]
+    fn shorten_long_names() {
+        let policy = FileNamePolicy::default().max_len(10);
+        assert_eq!(policy.sanitize("abcdefghijkl.csv").unwrap(), "abcdef.csv");
+        assert_eq!(
+            policy.sanitize("abcdefghijkl.backup").unwrap(),
+            "abcdefghij"
+        );
+        let policy = policy.max_len(9);
+        assert_eq!(policy.sanitize("äääääää.csv").unwrap(), "ää.csv");
+    }
+}
@@ -31,13 +31,25 @@
 //! ```
 //! kaggle::Authentication::Env;
 //! ```
+//!
+//! Credentials kept elsewhere, like a secrets manager, can be provided by
+//! implementing [`CredentialProvider`] and building the client with
+//! [`KaggleApiClientBuilder::build_async`].
+//!
+//! The commonly used types can be imported at once with
+//! `use kaggle::prelude::*;`.
 
 pub mod archive;
+pub mod batch;
 pub mod client;
 mod error;
+pub mod file_name;
 pub mod models;
 mod none_as_empty;
+pub mod prelude;
 pub mod query;
 pub mod request;
 
-pub use client::{Authentication, KaggleApiClient, KaggleApiClientBuilder};
+pub use client::{
+    Authentication, CredentialProvider, KaggleApiClient, KaggleApiClientBuilder, KaggleCredentials,
+};
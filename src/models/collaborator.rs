@@ -9,6 +9,9 @@ pub struct Collaborator {
 }
 
 impl Collaborator {
+    /// The roles kaggle accepts for dataset collaborators.
+    pub const ROLES: &'static [&'static str] = &["reader", "writer"];
+
     pub fn new<T: ToString, S: ToString>(username: T, role: S) -> Self {
         Self {
             username: username.to_string(),
@@ -23,4 +26,9 @@ impl Collaborator {
     pub fn role(&self) -> &str {
         &self.role
     }
+
+    /// Whether the role is one of [`Collaborator::ROLES`].
+    pub fn is_valid_role(&self) -> bool {
+        Self::ROLES.contains(&self.role.as_str())
+    }
 }
@@ -1,9 +1,10 @@
 use crate::archive::ArchiveMode;
 use crate::error::KaggleError;
-use crate::models::metadata::Metadata;
+use crate::models::metadata::{Metadata, ResourceInfo};
 use crate::models::{DatasetUploadFile, License};
 use crate::KaggleApiClient;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone)]
@@ -17,8 +18,22 @@ pub struct DatasetNew {
     pub is_private: bool,
     /// Whether or not a tabular dataset should be converted to csv
     pub convert_to_csv: bool,
-    /// How to archive the files beforehand
-    pub archive_mode: ArchiveMode,
+    /// How to archive directories beforehand, the
+    /// [`KaggleApiClientBuilder::default_archive_mode`] if not set
+    ///
+    /// [`KaggleApiClientBuilder::default_archive_mode`]: crate::KaggleApiClientBuilder::default_archive_mode
+    pub archive_mode: Option<ArchiveMode>,
+    /// Description and columns per uploaded file, keyed by its path relative
+    /// to the dataset folder, these take precedence over the resources of the
+    /// metadata
+    pub resource_info: HashMap<String, ResourceInfo>,
+    /// Subtitle that takes precedence over the subtitle of the metadata
+    pub subtitle: Option<String>,
+    /// Description that takes precedence over the description of the
+    /// metadata
+    pub description: Option<String>,
+    /// Keywords that take precedence over the keywords of the metadata
+    pub keywords: Option<Vec<String>>,
 }
 
 impl DatasetNew {
@@ -28,7 +43,11 @@ impl DatasetNew {
             metadata,
             is_private: true,
             convert_to_csv: true,
-            archive_mode: Default::default(),
+            archive_mode: None,
+            resource_info: HashMap::new(),
+            subtitle: None,
+            description: None,
+            keywords: None,
         }
     }
 
@@ -70,7 +89,7 @@ impl DatasetNew {
     }
 
     pub fn archive_mode(mut self, archive_mode: ArchiveMode) -> Self {
-        self.archive_mode = archive_mode;
+        self.archive_mode = Some(archive_mode);
         self
     }
 
@@ -78,6 +97,31 @@ impl DatasetNew {
         self.dataset_folder = Some(dataset_folder.as_ref().to_path_buf());
         self
     }
+
+    /// Attach a description and columns to the uploaded file at `path`,
+    /// relative to the dataset folder.
+    pub fn resource_info(mut self, path: impl ToString, info: ResourceInfo) -> Self {
+        self.resource_info.insert(path.to_string(), info);
+        self
+    }
+
+    /// Use `subtitle` instead of the subtitle of the metadata.
+    pub fn subtitle(mut self, subtitle: impl ToString) -> Self {
+        self.subtitle = Some(subtitle.to_string());
+        self
+    }
+
+    /// Use `description` instead of the description of the metadata.
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Use `keywords` instead of the keywords of the metadata.
+    pub fn keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = Some(keywords);
+        self
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -138,6 +182,10 @@ impl DatasetNewRequest {
         &self.files
     }
 
+    pub fn set_files(&mut self, files: Vec<DatasetUploadFile>) {
+        self.files = files;
+    }
+
     pub fn is_private(&self) -> bool {
         self.is_private
     }
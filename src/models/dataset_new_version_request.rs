@@ -23,6 +23,10 @@ pub struct DatasetNewVersionRequest {
     /// upon creating the new version
     #[serde(rename = "deleteOldVersions")]
     delete_old_versions: Option<bool>,
+    /// Whether the dataset should be private from this version on, the
+    /// visibility is left unchanged if not set
+    #[serde(rename = "isPrivate", skip_serializing_if = "Option::is_none", default)]
+    is_private: Option<bool>,
 }
 
 impl DatasetNewVersionRequest {
@@ -35,6 +39,7 @@ impl DatasetNewVersionRequest {
             convert_to_csv: None,
             category_ids: None,
             delete_old_versions: None,
+            is_private: None,
         }
     }
 
@@ -151,4 +156,36 @@ impl DatasetNewVersionRequest {
     pub fn reset_delete_old_versions(&mut self) {
         self.delete_old_versions = None;
     }
+
+    pub fn set_is_private(&mut self, is_private: bool) {
+        self.is_private = Some(is_private);
+    }
+
+    pub fn with_is_private(mut self, is_private: bool) -> DatasetNewVersionRequest {
+        self.is_private = Some(is_private);
+        self
+    }
+
+    pub fn is_private(&self) -> Option<&bool> {
+        self.is_private.as_ref()
+    }
+
+    pub fn reset_is_private(&mut self) {
+        self.is_private = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ser_visibility() {
+        let req = DatasetNewVersionRequest::new("notes".to_string());
+        let json = serde_json::to_value(&req).unwrap();
+        assert!(json.get("isPrivate").is_none());
+
+        let json = serde_json::to_value(req.with_is_private(false)).unwrap();
+        assert_eq!(json["isPrivate"], false);
+    }
 }
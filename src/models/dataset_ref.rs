@@ -0,0 +1,107 @@
+use crate::error::KaggleError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A `{owner}/{slug}` reference to a dataset or kernel, like the `ref` of a
+/// [`crate::models::extended::Dataset`].
+///
+/// It can be passed to every method that expects a dataset name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DatasetRef {
+    ref_: String,
+    /// Position of the `/`
+    split: usize,
+}
+
+impl DatasetRef {
+    pub fn new(owner: impl AsRef<str>, slug: impl AsRef<str>) -> Result<Self, KaggleError> {
+        format!("{}/{}", owner.as_ref(), slug.as_ref()).parse()
+    }
+
+    pub fn owner(&self) -> &str {
+        &self.ref_[..self.split]
+    }
+
+    pub fn slug(&self) -> &str {
+        &self.ref_[self.split + 1..]
+    }
+}
+
+/// Split a `{owner}/{slug}` reference into the owner and the slug.
+///
+/// # Errors
+///
+/// Fails unless the reference consists of exactly two non-empty parts without
+/// whitespace.
+pub fn split_ref(ref_: &str) -> Result<(&str, &str), KaggleError> {
+    let mut split = ref_.split('/');
+    match (split.next(), split.next(), split.next()) {
+        (Some(owner), Some(slug), None)
+            if !owner.is_empty() && !slug.is_empty() && !ref_.contains(char::is_whitespace) =>
+        {
+            Ok((owner, slug))
+        }
+        _ => Err(KaggleError::meta(format!(
+            "Invalid reference. expected form `{{owner}}/{{slug}}`, but got `{}`",
+            ref_
+        ))),
+    }
+}
+
+impl FromStr for DatasetRef {
+    type Err = KaggleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (owner, _) = split_ref(s)?;
+        Ok(Self {
+            ref_: s.to_string(),
+            split: owner.len(),
+        })
+    }
+}
+
+impl AsRef<str> for DatasetRef {
+    fn as_ref(&self) -> &str {
+        &self.ref_
+    }
+}
+
+impl fmt::Display for DatasetRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.ref_)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_ref() {
+        let dataset: DatasetRef = "unanimad/dataisbeautiful".parse().unwrap();
+        assert_eq!(dataset.owner(), "unanimad");
+        assert_eq!(dataset.slug(), "dataisbeautiful");
+        assert_eq!(dataset.to_string(), "unanimad/dataisbeautiful");
+        assert_eq!(
+            DatasetRef::new("unanimad", "dataisbeautiful").unwrap(),
+            dataset
+        );
+    }
+
+    #[test]
+    fn reject_malformed_ref() {
+        for ref_ in &[
+            "",
+            "dataset",
+            "/dataset",
+            "owner/",
+            "a/b/c",
+            "owner/my dataset",
+        ] {
+            match split_ref(ref_) {
+                Err(KaggleError::Metadata { msg }) => assert!(msg.contains(ref_)),
+                res => panic!("expected error for `{}`, got {:?}", ref_, res),
+            }
+        }
+    }
+}
@@ -10,6 +10,13 @@ pub struct Error {
 }
 
 impl Error {
+    pub(crate) fn new(code: i32, message: impl ToString) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+
     pub fn code(&self) -> i32 {
         self.code
     }
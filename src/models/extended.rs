@@ -1,15 +1,20 @@
+use crate::models::metadata::Metadata;
 use crate::models::{Collaborator, DatasetColumn, License};
-use crate::query::{KernelType, Language, PushKernelType};
+use crate::query::{KernelType, Language, PushKernelType, PushLanguageType};
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Competition {
     #[serde(rename = "ref")]
     pub ref_: String,
+    #[serde(default)]
     pub tags: Vec<Tag>,
+    #[serde(default)]
     pub description: String,
     pub id: i64,
     pub title: String,
@@ -20,33 +25,296 @@ pub struct Competition {
     pub reward: String,
     pub organization_name: Option<String>,
     pub organization_ref: Option<String>,
+    #[serde(default)]
     pub kernel_count: i64,
+    #[serde(default)]
     pub team_count: i64,
+    #[serde(default)]
     pub user_has_entered: bool,
     pub user_rank: Option<i64>,
-    #[serde(with = "crate::models::extended::date_serializer_opt")]
+    #[serde(with = "crate::models::extended::date_serializer_opt", default)]
     pub merger_deadline: Option<NaiveDateTime>,
-    #[serde(with = "crate::models::extended::date_serializer_opt")]
+    #[serde(with = "crate::models::extended::date_serializer_opt", default)]
     pub new_entrant_deadline: Option<NaiveDateTime>,
     #[serde(with = "crate::models::extended::date_serializer")]
     pub enabled_date: NaiveDateTime,
+    #[serde(default)]
     pub max_daily_submissions: i64,
     pub max_team_size: Option<i64>,
+    #[serde(default)]
     pub evaluation_metric: String,
+    #[serde(default)]
     pub awards_points: bool,
+    #[serde(default)]
     pub is_kernels_submissions_only: bool,
+    #[serde(default)]
     pub submissions_disabled: bool,
 }
 
+impl Competition {
+    /// Whether the competition only accepts kernel outputs, see
+    /// [`crate::KaggleApiClient::competition_submit_code`].
+    pub fn is_code_competition(&self) -> bool {
+        self.is_kernels_submissions_only
+    }
+
+    /// Whether a higher or lower score is better, judged by the name of the
+    /// evaluation metric. Unknown metrics are higher-better.
+    pub fn score_order(&self) -> ScoreOrder {
+        ScoreOrder::from_metric(&self.evaluation_metric).unwrap_or_default()
+    }
+
+    /// Whether the competition is tagged with `name`, compared case
+    /// insensitive to the name and the ref of its tags.
+    pub fn has_tag(&self, name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+        self.tags
+            .iter()
+            .any(|tag| tag.name.eq_ignore_ascii_case(name) || tag.ref_.eq_ignore_ascii_case(name))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubmitResult {
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
 }
 
+impl SubmitResult {
+    /// The message kaggle answered the submit with.
+    pub fn message(&self) -> Option<&str> {
+        self.extra
+            .get("message")
+            .and_then(serde_json::Value::as_str)
+    }
+
+    /// The ref of the created submission, if kaggle sent it.
+    pub fn submission_ref(&self) -> Option<i64> {
+        self.extra.get("ref").and_then(serde_json::Value::as_i64)
+    }
+}
+
+/// What [`crate::KaggleApiClient::competition_submit`] would upload, checked
+/// without submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionReport {
+    /// The name the file would be submitted as
+    pub file_name: String,
+    /// The number of bytes that would be uploaded
+    pub content_length: u64,
+    /// Whether the file would be gzip compressed before the upload
+    pub compressed: bool,
+    /// How the file differs from the sample submission, if it was checked
+    pub sample_mismatch: Option<String>,
+}
+
+impl SubmissionReport {
+    /// Whether no mismatch with the sample submission was found.
+    pub fn is_valid(&self) -> bool {
+        self.sample_mismatch.is_none()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Submission {
+    #[serde(rename = "ref")]
+    pub ref_: i64,
+    pub total_bytes: Option<i64>,
+    #[serde(with = "crate::models::extended::date_serializer")]
+    pub date: NaiveDateTime,
+    pub description: Option<String>,
+    pub error_description: Option<String>,
+    pub file_name: Option<String>,
+    pub public_score: Option<String>,
+    pub private_score: Option<String>,
+    pub status: SubmissionStatus,
+    pub submitted_by: Option<String>,
+    pub submitted_by_ref: Option<String>,
+    pub team_name: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    pub url: Option<String>,
+}
+
+impl Submission {
+    /// Whether the submission was scored successfully.
+    pub fn is_complete(&self) -> bool {
+        self.status == SubmissionStatus::Complete
+    }
+
+    /// The public score parsed as number, if available.
+    pub fn public_score(&self) -> Option<f64> {
+        self.public_score
+            .as_ref()
+            .and_then(|s| s.trim().parse().ok())
+    }
+
+    /// The private score parsed as number, if available.
+    pub fn private_score(&self) -> Option<f64> {
+        self.private_score
+            .as_ref()
+            .and_then(|s| s.trim().parse().ok())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum SubmissionStatus {
+    Pending,
+    Complete,
+    Error,
+    /// Any other status reported by kaggle
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl SubmissionStatus {
+    /// The status as kaggle reports it.
+    pub fn as_str(&self) -> &str {
+        match self {
+            SubmissionStatus::Pending => "pending",
+            SubmissionStatus::Complete => "complete",
+            SubmissionStatus::Error => "error",
+            SubmissionStatus::Other(status) => status,
+        }
+    }
+}
+
+/// The processing state of a dataset after a new version was created.
+///
+/// Kaggle isn't consistent about the case of the status, so it is read
+/// case insensitively.
+#[derive(Debug, Clone, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DatasetStatus {
+    Ready,
+    Processing,
+    Error,
+    /// Any other status reported by kaggle
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl DatasetStatus {
+    /// Whether kaggle is done with the dataset, successful or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, DatasetStatus::Ready | DatasetStatus::Error)
+    }
+}
+
+impl<'de> Deserialize<'de> for DatasetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let status = String::deserialize(deserializer)?;
+        Ok(match status.to_lowercase().as_str() {
+            "ready" => DatasetStatus::Ready,
+            "processing" => DatasetStatus::Processing,
+            "error" => DatasetStatus::Error,
+            _ => DatasetStatus::Other(status),
+        })
+    }
+}
+
+/// The state of the latest run of a kernel.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum KernelRunStatus {
+    Queued,
+    Running,
+    Complete,
+    Error,
+    CancelRequested,
+    CancelAcknowledged,
+    /// Any other status reported by kaggle
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Whether a higher or a lower score ranks better, which depends on the
+/// evaluation metric of a competition.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScoreOrder {
+    /// Accuracy, AUC, F1 and most other metrics
+    #[default]
+    HigherIsBetter,
+    /// Error and loss metrics like RMSE, MAE or log loss
+    LowerIsBetter,
+}
+
+impl ScoreOrder {
+    /// Metrics, lowercase and without spaces, dashes and underscores, where
+    /// a lower score is better.
+    const LOWER_IS_BETTER: &'static [&'static str] = &[
+        "rmse",
+        "rmsle",
+        "mse",
+        "mae",
+        "mape",
+        "smape",
+        "logloss",
+        "multiclassloss",
+        "multiclasslogloss",
+        "meanabsoluteerror",
+        "meansquarederror",
+        "rootmeansquarederror",
+        "rootmeansquaredlogarithmicerror",
+        "meancolumnwiselogloss",
+        "levenshteindistance",
+        "wordeditdistance",
+    ];
+
+    /// The order of a known evaluation metric, e.g. `RMSE` or `AUC`.
+    pub fn from_metric(metric: impl AsRef<str>) -> Option<Self> {
+        let metric: String = metric
+            .as_ref()
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect();
+        if metric.is_empty() {
+            None
+        } else if Self::LOWER_IS_BETTER.contains(&metric.as_str()) {
+            Some(ScoreOrder::LowerIsBetter)
+        } else {
+            Some(ScoreOrder::HigherIsBetter)
+        }
+    }
+
+    /// Compare two scores so that the better one is greater.
+    pub fn compare(self, a: f64, b: f64) -> Ordering {
+        match self {
+            ScoreOrder::HigherIsBetter => a.total_cmp(&b),
+            ScoreOrder::LowerIsBetter => b.total_cmp(&a),
+        }
+    }
+}
+
+/// Returns the complete submission with the highest public score.
+pub fn best_submission(submissions: &[Submission]) -> Option<&Submission> {
+    best_submission_by(submissions, ScoreOrder::HigherIsBetter)
+}
+
+/// Returns the complete submission with the best public score in `order`.
+pub fn best_submission_by(submissions: &[Submission], order: ScoreOrder) -> Option<&Submission> {
+    submissions
+        .iter()
+        .filter(|s| s.is_complete())
+        .filter_map(|s| s.public_score().map(|score| (s, score)))
+        .max_by(|(_, a), (_, b)| order.compare(*a, *b))
+        .map(|(s, _)| s)
+}
+
+/// Sorts the submissions by their submission date, oldest first.
+pub fn sort_by_date(submissions: &mut [Submission]) {
+    submissions.sort_by_key(|s| s.date);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntry {
     pub team_id: i64,
     pub team_name: String,
     #[serde(with = "crate::models::extended::date_serializer")]
@@ -54,9 +322,25 @@ pub struct Submission {
     pub score: String,
 }
 
+impl LeaderboardEntry {
+    /// The score parsed as number, if possible.
+    pub fn score(&self) -> Option<f64> {
+        self.score.trim().parse().ok()
+    }
+}
+
+/// Sorts the leaderboard entries best first, entries without a numeric score
+/// last.
+pub fn sort_leaderboard(entries: &mut [LeaderboardEntry], order: ScoreOrder) {
+    entries.sort_by(|a, b| match (a.score(), b.score()) {
+        (Some(a), Some(b)) => order.compare(b, a),
+        (a, b) => b.is_some().cmp(&a.is_some()),
+    });
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeaderBoard {
-    pub submissions: Vec<Submission>,
+    pub submissions: Vec<LeaderboardEntry>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,18 +376,43 @@ pub struct Dataset {
     pub usability_rating: f64,
 }
 
+/// The details of a single dataset, as shown on its page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatasetView {
+    #[serde(rename = "ref")]
+    pub ref_: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub description: Option<String>,
+    pub license_name: Option<String>,
+    /// The size of all files of the current version in bytes
+    pub total_bytes: Option<i64>,
+    #[serde(with = "crate::models::extended::date_serializer_opt", default)]
+    pub last_updated: Option<NaiveDateTime>,
+    pub download_count: Option<i64>,
+    pub vote_count: Option<i64>,
+    pub current_version_number: Option<i64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Tag {
     #[serde(rename = "ref")]
     pub ref_: String,
+    #[serde(default)]
     pub competition_count: i64,
+    #[serde(default)]
     pub dataset_count: i64,
     pub description: Option<String>,
+    #[serde(default)]
     pub full_path: String,
+    #[serde(default)]
     pub is_automatic: bool,
     pub name: String,
+    #[serde(default)]
     pub script_count: i64,
+    #[serde(default)]
     pub total_count: i64,
 }
 
@@ -141,13 +450,15 @@ pub struct MetadataData {
 pub struct File {
     #[serde(rename = "ref")]
     pub ref_: String,
-    #[serde(with = "crate::models::extended::date_serializer_opt")]
+    #[serde(with = "crate::models::extended::date_serializer_opt", default)]
     pub creation_date: Option<NaiveDateTime>,
     pub dataset_ref: Option<String>,
     pub description: Option<String>,
     pub file_type: Option<String>,
     pub name: String,
     pub owner_ref: Option<String>,
+    /// The size of the file in bytes, some endpoints name it `size`
+    #[serde(alias = "size")]
     pub total_bytes: i64,
     pub url: String,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
@@ -172,12 +483,40 @@ pub struct FileUploadInfo {
     pub create_url: String,
 }
 
+/// The answer to a new dataset version, kaggle reports a failed version
+/// with status `error` and the `error` message instead of an error status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DatasetNewVersionResponse {
+    /// If an error occurred, this is None
+    #[serde(rename = "ref", default)]
+    pub ref_: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub invalid_tags: Vec<serde_json::Value>,
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
 }
 
+impl DatasetNewVersionResponse {
+    pub fn is_success(&self) -> bool {
+        self.status == "ok"
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.status == "error"
+    }
+
+    pub fn cause(&self) -> Option<&String> {
+        self.error.as_ref()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatasetNewResponse {
     /// If an error occurred, this is None
@@ -207,8 +546,25 @@ impl DatasetNewResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListFilesResult {
+    /// Set instead of an error status if listing failed, `null` otherwise
+    #[serde(default)]
     pub error_message: Option<String>,
     pub dataset_files: Vec<DatasetFile>,
+    /// Token to request the next page of files, if there are more
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub next_page_token: Option<String>,
+}
+
+impl ListFilesResult {
+    /// The number of files of this page.
+    pub fn file_count(&self) -> usize {
+        self.dataset_files.len()
+    }
+
+    /// The size of all files of this page in bytes.
+    pub fn total_bytes(&self) -> i64 {
+        self.dataset_files.iter().map(|f| f.total_bytes).sum()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -268,6 +624,34 @@ impl KernelPullResponse {
             .file_extension(&self.blob.language)
             .map(|ext| format!("{}{}", self.blob.slug, ext))
     }
+
+    /// The `kernel-metadata.json` of the pulled kernel, which refers to its
+    /// source as `code_file`.
+    pub fn kernel_metadata(&self, code_file: impl ToString) -> Metadata {
+        let meta = &self.metadata;
+        let language = match self.blob.language {
+            Language::Python => Some(PushLanguageType::Python),
+            Language::R => Some(PushLanguageType::R),
+            Language::Rmarkdown => Some(PushLanguageType::Rmarkdown),
+            _ => None,
+        };
+        Metadata {
+            id: meta.ref_.trim_start_matches('/').to_string(),
+            id_no: i32::try_from(meta.id).ok(),
+            title: meta.title.clone(),
+            code_file: Some(code_file.to_string()),
+            language,
+            kernel_type: Some(self.blob.kernel_type.clone()),
+            is_private: meta.is_private,
+            enable_gpu: meta.enable_gpu,
+            enable_internet: meta.enable_internet,
+            keywords: meta.category_ids.clone(),
+            dataset_sources: meta.dataset_data_sources.clone(),
+            kernel_sources: meta.kernel_data_sources.clone(),
+            competition_sources: meta.competition_data_sources.clone(),
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -302,12 +686,77 @@ pub struct KernelBlob {
     pub source: String,
 }
 
+/// The answer to a kernel push, a rejected push only sets `error`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct KernelPushResponse {
+    #[serde(rename = "ref", default)]
+    pub ref_: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    /// The version the push created
+    #[serde(default)]
+    pub version_number: Option<i64>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub invalid_tags: Vec<serde_json::Value>,
+    /// Sources of the metadata that kaggle could not find
+    #[serde(default)]
+    pub invalid_dataset_sources: Vec<String>,
+    #[serde(default)]
+    pub invalid_competition_sources: Vec<String>,
+    #[serde(default)]
+    pub invalid_kernel_sources: Vec<String>,
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
 }
 
+impl KernelPushResponse {
+    pub fn is_error(&self) -> bool {
+        self.cause().is_some()
+    }
+
+    pub fn cause(&self) -> Option<&String> {
+        self.error.as_ref().filter(|err| !err.is_empty())
+    }
+}
+
+/// The answer to a metadata update, listing every rejected setting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatasetUpdateSettingsResponse {
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
+impl DatasetUpdateSettingsResponse {
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// The answer of [`crate::KaggleApiClient::kernel_status`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KernelStatus {
+    pub status: KernelRunStatus,
+    /// Why the run failed, if it did
+    #[serde(default)]
+    pub failure_message: Option<String>,
+}
+
+impl KernelStatus {
+    /// Whether the run is over, successful or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            KernelRunStatus::Complete
+                | KernelRunStatus::Error
+                | KernelRunStatus::CancelAcknowledged
+        )
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KernelOutput {
     #[serde(default)]
@@ -319,12 +768,8 @@ pub struct KernelOutput {
 pub struct KernelOutputFile {
     #[serde(rename = "fileName")]
     pub file_name: String,
-    pub url: DownloadResponse,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DownloadResponse {
-    pub content: String,
+    /// Where to download the file from
+    pub url: String,
 }
 
 mod date_serializer {
@@ -388,3 +833,231 @@ mod date_serializer_opt {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(ref_: i64, date: &str, status: &str, score: Option<&str>) -> Submission {
+        serde_json::from_value(serde_json::json!({
+            "ref": ref_,
+            "date": date,
+            "status": status,
+            "publicScore": score,
+        }))
+        .unwrap()
+    }
+
+    fn submissions() -> Vec<Submission> {
+        vec![
+            submission(1, "2020-04-02T12:00:00Z", "complete", Some("0.75")),
+            submission(2, "2020-04-01T12:00:00Z", "complete", Some("0.81")),
+            submission(3, "2020-04-04T12:00:00Z", "error", Some("0.99")),
+            submission(4, "2020-04-03T12:00:00Z", "pending", None),
+            submission(5, "2020-03-30T12:00:00Z", "complete", None),
+        ]
+    }
+
+    #[test]
+    fn competition_tags() {
+        let competition: Competition = serde_json::from_value(serde_json::json!({
+            "ref": "titanic",
+            "tags": [{
+                "ref": "binary classification",
+                "name": "Binary Classification",
+                "description": null,
+                "fullPath": "task > binary classification",
+                "competitionCount": 120,
+                "datasetCount": 1500,
+                "scriptCount": 9000,
+                "totalCount": 10620,
+                "isAutomatic": false
+            }, {
+                "ref": "tabular data",
+                "name": "Tabular Data",
+                "description": null
+            }],
+            "description": "Start here!",
+            "id": 3136,
+            "title": "Titanic - Machine Learning from Disaster",
+            "url": "https://www.kaggle.com/competitions/titanic",
+            "deadline": "2030-01-01T00:00:00Z",
+            "category": "Getting Started",
+            "reward": "Knowledge",
+            "organizationName": "Kaggle",
+            "organizationRef": null,
+            "kernelCount": 0,
+            "teamCount": 15000,
+            "userHasEntered": false,
+            "userRank": null,
+            "mergerDeadline": null,
+            "newEntrantDeadline": null,
+            "enabledDate": "2012-09-28T21:13:33Z",
+            "maxDailySubmissions": 10,
+            "maxTeamSize": 1,
+            "evaluationMetric": "Categorization Accuracy",
+            "awardsPoints": false,
+            "isKernelsSubmissionsOnly": false,
+            "submissionsDisabled": false
+        }))
+        .unwrap();
+
+        assert_eq!(competition.tags.len(), 2);
+        assert_eq!(competition.tags[0].total_count, 10620);
+        assert!(competition.has_tag("binary classification"));
+        assert!(competition.has_tag("TABULAR DATA"));
+        assert!(!competition.has_tag("computer vision"));
+    }
+
+    #[test]
+    fn de_sparse_competition() {
+        let competition: Competition = serde_json::from_value(serde_json::json!({
+            "ref": "https://www.kaggle.com/competitions/titanic",
+            "id": 3136,
+            "title": "Titanic - Machine Learning from Disaster",
+            "url": "https://www.kaggle.com/competitions/titanic",
+            "deadline": "2030-01-01T00:00:00Z",
+            "category": "Getting Started",
+            "reward": "Knowledge",
+            "enabledDate": "2012-09-28T21:13:33Z",
+            "mergerDeadline": "2029-12-25T00:00:00Z"
+        }))
+        .unwrap();
+
+        assert_eq!(competition.id, 3136);
+        assert!(competition.tags.is_empty());
+        assert!(!competition.user_has_entered);
+        assert_eq!(
+            competition.merger_deadline.unwrap().to_string(),
+            "2029-12-25 00:00:00"
+        );
+        assert_eq!(competition.new_entrant_deadline, None);
+        assert_eq!(competition.organization_name, None);
+    }
+
+    #[test]
+    fn de_competition_data_file() {
+        let files: Vec<File> = serde_json::from_str(
+            r#"[{
+                "ref": "train.csv",
+                "datasetRef": null,
+                "ownerRef": null,
+                "name": "train.csv",
+                "creationDate": "2019-09-23T17:06:29.513Z",
+                "description": "The training set",
+                "fileType": ".csv",
+                "url": "https://www.kaggle.com/c/titanic/download/train.csv",
+                "totalBytes": 61194,
+                "columns": []
+            }, {
+                "ref": "test.csv",
+                "name": "test.csv",
+                "url": "https://www.kaggle.com/c/titanic/download/test.csv",
+                "size": 28629
+            }]"#,
+        )
+        .unwrap();
+
+        let train = &files[0];
+        assert_eq!(train.name, "train.csv");
+        assert_eq!(train.total_bytes, 61194);
+        assert_eq!(
+            train.creation_date.unwrap().to_string(),
+            "2019-09-23 17:06:29.513"
+        );
+        assert_eq!(train.description.as_deref(), Some("The training set"));
+        assert_eq!(train.file_type.as_deref(), Some(".csv"));
+        assert_eq!(
+            train.url,
+            "https://www.kaggle.com/c/titanic/download/train.csv"
+        );
+
+        let test = &files[1];
+        assert_eq!(test.total_bytes, 28629);
+        assert!(test.creation_date.is_none());
+        assert!(test.description.is_none());
+    }
+
+    #[test]
+    fn best_complete_submission() {
+        let submissions = submissions();
+        assert_eq!(best_submission(&submissions).unwrap().ref_, 2);
+        assert!(best_submission(&submissions[2..]).is_none());
+    }
+
+    #[test]
+    fn best_submission_by_metric() {
+        let submissions = submissions();
+        let lowest = best_submission_by(&submissions, ScoreOrder::LowerIsBetter).unwrap();
+        assert_eq!(lowest.ref_, 1);
+        let highest = best_submission_by(&submissions, ScoreOrder::HigherIsBetter).unwrap();
+        assert_eq!(highest.ref_, 2);
+
+        assert_eq!(
+            ScoreOrder::from_metric("Root Mean Squared Error"),
+            Some(ScoreOrder::LowerIsBetter)
+        );
+        assert_eq!(
+            ScoreOrder::from_metric("RMSE"),
+            Some(ScoreOrder::LowerIsBetter)
+        );
+        assert_eq!(
+            ScoreOrder::from_metric("AUC"),
+            Some(ScoreOrder::HigherIsBetter)
+        );
+        assert_eq!(ScoreOrder::from_metric(""), None);
+    }
+
+    #[test]
+    fn leaderboard_sorted_by_metric() {
+        let entry = |team_id, score: &str| LeaderboardEntry {
+            team_id,
+            team_name: team_id.to_string(),
+            submission_date: NaiveDateTime::default(),
+            score: score.to_string(),
+        };
+        let mut entries = [
+            entry(1, "0.5"),
+            entry(2, ""),
+            entry(3, "0.25"),
+            entry(4, "0.75"),
+        ];
+
+        sort_leaderboard(&mut entries, ScoreOrder::LowerIsBetter);
+        let teams: Vec<_> = entries.iter().map(|e| e.team_id).collect();
+        assert_eq!(teams, [3, 1, 4, 2]);
+
+        sort_leaderboard(&mut entries, ScoreOrder::HigherIsBetter);
+        let teams: Vec<_> = entries.iter().map(|e| e.team_id).collect();
+        assert_eq!(teams, [4, 1, 3, 2]);
+    }
+
+    #[test]
+    fn submissions_sorted_by_date() {
+        let mut submissions = submissions();
+        sort_by_date(&mut submissions);
+        let refs: Vec<_> = submissions.iter().map(|s| s.ref_).collect();
+        assert_eq!(refs, vec![5, 2, 1, 4, 3]);
+    }
+
+    #[test]
+    fn de_error_responses() {
+        let version: DatasetNewVersionResponse = serde_json::from_str(
+            r#"{"ref":null,"url":null,"status":"error","error":"Invalid license","invalidTags":[]}"#,
+        )
+        .unwrap();
+        assert!(version.is_error());
+        assert_eq!(version.cause().map(String::as_str), Some("Invalid license"));
+
+        let push: KernelPushResponse = serde_json::from_str(
+            r#"{"error":"","versionNumber":3,"invalidDatasetSources":["user/missing"]}"#,
+        )
+        .unwrap();
+        assert!(!push.is_error());
+        assert_eq!(push.invalid_dataset_sources, vec!["user/missing"]);
+
+        let settings: DatasetUpdateSettingsResponse =
+            serde_json::from_str(r#"{"errors":["Invalid title"]}"#).unwrap();
+        assert!(!settings.is_success());
+    }
+}
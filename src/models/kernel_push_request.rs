@@ -277,3 +277,39 @@ impl KernelPushRequest {
         self.category_ids = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ser_minimal_request() {
+        let req = KernelPushRequest::new("print('hello')".to_string())
+            .with_slug("name/my-kernel".to_string())
+            .with_language(PushLanguageType::Python)
+            .with_kernel_type(PushKernelType::Script);
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "id": null,
+                "slug": "name/my-kernel",
+                "newTitle": null,
+                "text": "print('hello')",
+                "language": "python",
+                "kernelType": "script",
+                "isPrivate": null,
+                "enableGpu": null,
+                "enableInternet": null,
+                "datasetDataSources": null,
+                "competitionDataSources": null,
+                "kernelDataSources": null,
+                "categoryIds": null,
+            })
+        );
+
+        let json = serde_json::to_value(KernelPushRequest::new(String::new())).unwrap();
+        assert_eq!(json["language"], "");
+        assert_eq!(json["kernelType"], "");
+    }
+}
@@ -3,9 +3,9 @@ use crate::models::{Collaborator, DatasetColumn, DatasetUpdateSettingsRequest, L
 use crate::query::{PushKernelType, PushLanguageType};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Component, Path};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Metadata {
     pub title: String,
     pub id: String,
@@ -96,13 +96,49 @@ impl Metadata {
         Ok(())
     }
 
+    pub fn is_collaborators_valid(&self) -> Result<(), KaggleError> {
+        for collaborator in &self.collaborators {
+            if !collaborator.is_valid_role() {
+                return Err(KaggleError::meta(format!(
+                    "Invalid role `{}` for collaborator `{}`, expected one of {:?}",
+                    collaborator.role(),
+                    collaborator.username(),
+                    Collaborator::ROLES
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// A dataset can only be made public if it is licensed.
+    pub fn is_visibility_valid(&self) -> Result<(), KaggleError> {
+        if self.is_private == Some(false) && self.licenses.is_empty() {
+            return Err(KaggleError::meta(
+                "A license is required to make the dataset public",
+            ));
+        }
+        Ok(())
+    }
+
     /// Validate resources is a wrapper to validate the existence of files and
     /// that there are no duplicates for a folder and set of resources.
     pub fn validate_resource(&self, root: impl AsRef<Path>) -> Result<(), KaggleError> {
         let root = root.as_ref();
         let mut unique = HashSet::with_capacity(self.resources.len());
         for resource in &self.resources {
-            let file = root.join(&resource.path);
+            let path = Path::new(&resource.path);
+            if path.has_root()
+                || path
+                    .components()
+                    .any(|c| matches!(c, Component::ParentDir | Component::Prefix(_)))
+            {
+                return Err(KaggleError::meta(format!(
+                    "Resource path {} must be relative to the dataset folder and must not \
+                     contain `..`",
+                    resource.path
+                )));
+            }
+            let file = root.join(path);
             if !file.exists() {
                 return Err(KaggleError::FileNotFound(file));
             }
@@ -150,6 +186,30 @@ pub struct Resource {
     pub schema: Option<Schema>,
 }
 
+/// Description and columns of a single uploaded file, set independently of
+/// the resources in the metadata file.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceInfo {
+    pub description: Option<String>,
+    pub columns: Vec<DatasetColumn>,
+}
+
+impl ResourceInfo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn columns(mut self, columns: Vec<DatasetColumn>) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Schema {
     pub fields: Vec<Field>,
@@ -208,3 +268,62 @@ pub struct Field {
     #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
     pub type_field: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(collaborators: &str) -> Metadata {
+        serde_json::from_str(&format!(
+            r#"{{"title":"title","id":"user/dataset","collaborators":{}}}"#,
+            collaborators
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn collaborators_in_settings() {
+        let metadata = metadata(r#"[{"username":"friend","role":"writer"}]"#);
+        metadata.is_collaborators_valid().unwrap();
+
+        let settings: DatasetUpdateSettingsRequest = metadata.into();
+        let json = serde_json::to_value(&settings).unwrap();
+        assert_eq!(
+            json["collaborators"],
+            serde_json::json!([{"username": "friend", "role": "writer"}])
+        );
+    }
+
+    #[test]
+    fn invalid_collaborator_role() {
+        let metadata = metadata(r#"[{"username":"friend","role":"owner"}]"#);
+        assert!(metadata.is_collaborators_valid().is_err());
+    }
+
+    #[test]
+    fn resource_outside_dataset_folder() {
+        let root = std::env::temp_dir();
+        for path in &["../secret", "data/../../secret", "/etc/passwd"] {
+            let mut metadata = metadata("[]");
+            metadata.resources.push(Resource {
+                path: path.to_string(),
+                description: None,
+                schema: None,
+            });
+            match metadata.validate_resource(&root) {
+                Err(KaggleError::Metadata { msg }) => assert!(msg.contains(path)),
+                res => panic!("expected metadata error for {}, got {:?}", path, res),
+            }
+        }
+    }
+
+    #[test]
+    fn public_requires_license() {
+        let mut metadata = metadata("[]");
+        metadata.is_private = Some(false);
+        assert!(metadata.is_visibility_valid().is_err());
+
+        metadata.licenses.push(License::Cc010);
+        metadata.is_visibility_valid().unwrap();
+    }
+}
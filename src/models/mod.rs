@@ -2,6 +2,7 @@ pub use self::collaborator::Collaborator;
 pub use self::dataset_column::DatasetColumn;
 pub use self::dataset_new_request::{DatasetNew, DatasetNewRequest};
 pub use self::dataset_new_version_request::DatasetNewVersionRequest;
+pub use self::dataset_ref::{split_ref, DatasetRef};
 pub use self::dataset_update_settings_request::DatasetUpdateSettingsRequest;
 pub use self::dataset_upload_file::DatasetUploadFile;
 pub use self::error::Error;
@@ -12,6 +13,7 @@ mod collaborator;
 mod dataset_column;
 mod dataset_new_request;
 mod dataset_new_version_request;
+mod dataset_ref;
 mod dataset_update_settings_request;
 mod dataset_upload_file;
 mod error;
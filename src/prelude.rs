@@ -0,0 +1,46 @@
+//! The types needed for most interactions with the Kaggle api.
+//!
+//! ```
+//! use kaggle::prelude::*;
+//!
+//! fn _names(
+//!     _: KaggleApiClient,
+//!     _: KaggleApiClientBuilder,
+//!     _: Authentication,
+//!     _: ArchiveMode,
+//!     _: CompetitionsList,
+//!     _: DatasetsList,
+//!     _: KernelsList,
+//!     _: KernelPullRequest,
+//!     _: KaggleError,
+//!     _: ApiError,
+//!     _: Competition,
+//!     _: Submission,
+//!     _: SubmissionStatus,
+//!     _: LeaderboardEntry,
+//!     _: Dataset,
+//!     _: DatasetFile,
+//!     _: File,
+//!     _: Kernel,
+//!     _: Metadata,
+//!     _: DatasetRef,
+//! ) {
+//! }
+//! ```
+
+pub use crate::archive::ArchiveMode;
+pub use crate::client::{Authentication, KaggleApiClient, KaggleApiClientBuilder};
+pub use crate::error::{ApiError, KaggleError};
+pub use crate::models::extended::{
+    Competition,
+    Dataset,
+    DatasetFile,
+    File,
+    Kernel,
+    LeaderboardEntry,
+    Submission,
+    SubmissionStatus,
+};
+pub use crate::models::metadata::Metadata;
+pub use crate::models::DatasetRef;
+pub use crate::request::{CompetitionsList, DatasetsList, KernelPullRequest, KernelsList};
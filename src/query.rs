@@ -12,7 +12,7 @@ impl PushKernelType {
         match self {
             PushKernelType::Script => match language {
                 Language::Python => Some(".py"),
-                Language::R => Some(".r"),
+                Language::R => Some(".R"),
                 Language::Sqlite => Some(".sqlite"),
                 Language::Julia => Some(".jl"),
                 Language::Rmarkdown => Some(".Rmd"),
@@ -47,6 +47,9 @@ pub enum Language {
     Sqlite,
     Julia,
     Rmarkdown,
+    /// Any other language identifier, passed through as is
+    #[serde(untagged)]
+    Other(String),
 }
 
 
@@ -58,6 +61,9 @@ pub enum KernelType {
     All,
     Script,
     Notebook,
+    /// Any other kernel type, passed through as is
+    #[serde(untagged)]
+    Other(String),
 }
 
 
@@ -69,6 +75,9 @@ pub enum OutputType {
     All,
     Visualization,
     Data,
+    /// Any other output type, passed through as is
+    #[serde(untagged)]
+    Other(String),
 }
 
 
@@ -87,6 +96,9 @@ pub enum SortBy {
     ScoreDescending,
     ViewCount,
     VoteCount,
+    /// Any other sort option, passed through as is
+    #[serde(untagged)]
+    Other(String),
 }
 
 
@@ -99,6 +111,9 @@ pub enum CompetitionGroup {
     General,
     Entered,
     InClass,
+    /// Any other group, passed through as is
+    #[serde(untagged)]
+    Other(String),
 }
 
 
@@ -114,6 +129,9 @@ pub enum CompetitionCategory {
     GettingStarted,
     Masters,
     Playground,
+    /// Any other category, passed through as is
+    #[serde(untagged)]
+    Other(String),
 }
 
 
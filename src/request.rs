@@ -1,5 +1,6 @@
 use serde::Serialize;
 
+use crate::error::KaggleError;
 use crate::query::{
     CompetitionCategory,
     CompetitionGroup,
@@ -19,18 +20,18 @@ use std::path::{Path, PathBuf};
 #[serde(rename_all = "camelCase")]
 pub struct CompetitionsList {
     /// Group to filter result to
-    #[serde(with = "crate::none_as_empty")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     group: Option<CompetitionGroup>,
     /// Category to filter result to
-    #[serde(with = "crate::none_as_empty")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     category: Option<CompetitionCategory>,
     /// How to sort the result
-    #[serde(with = "crate::none_as_empty")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     sort_by: Option<CompetitionSortBy>,
     /// The page to return.
     page: usize,
-    /// Search term to use (default is empty string)
-    #[serde(with = "crate::none_as_empty")]
+    /// Search term to use, unset filters are left out of the query
+    #[serde(skip_serializing_if = "Option::is_none")]
     search: Option<String>,
 }
 
@@ -64,6 +65,67 @@ impl CompetitionsList {
         self.search = Some(search.to_string());
         self
     }
+
+    /// The same query for the following page.
+    pub fn next_page(mut self) -> Self {
+        self.page += 1;
+        self
+    }
+
+    /// Whether the query uses a [`CompetitionCategory::Other`].
+    pub(crate) fn has_custom_category(&self) -> bool {
+        matches!(self.category, Some(CompetitionCategory::Other(_)))
+    }
+
+    /// Whether the query uses a [`CompetitionGroup::Other`].
+    pub(crate) fn has_custom_group(&self) -> bool {
+        matches!(self.group, Some(CompetitionGroup::Other(_)))
+    }
+
+    /// Check that a custom [`CompetitionCategory::Other`] is one of the
+    /// `categories` kaggle knows about.
+    ///
+    /// Categories are compared case insensitive and ignoring whitespace, so
+    /// `Getting Started` matches `gettingStarted`.
+    pub fn validate_category(&self, categories: &[String]) -> Result<(), KaggleError> {
+        if let Some(CompetitionCategory::Other(category)) = &self.category {
+            validate_name("category", category, categories)?;
+        }
+        Ok(())
+    }
+
+    /// Check that a custom [`CompetitionGroup::Other`] is one of the `groups`
+    /// kaggle knows about, compared like in
+    /// [`CompetitionsList::validate_category`].
+    pub fn validate_group(&self, groups: &[String]) -> Result<(), KaggleError> {
+        if let Some(CompetitionGroup::Other(group)) = &self.group {
+            validate_name("group", group, groups)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that `name` is one of the `known` names of a competition filter,
+/// ignoring case and whitespace.
+fn validate_name(kind: &str, name: &str, known: &[String]) -> Result<(), KaggleError> {
+    fn normalize(s: &str) -> String {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    let normalized = normalize(name);
+    if known.iter().any(|k| normalize(k) == normalized) {
+        Ok(())
+    } else {
+        Err(KaggleError::Query {
+            msg: format!(
+                "Unknown competition {} `{}`, expected one of {:?}",
+                kind, name, known
+            ),
+        })
+    }
 }
 
 impl Default for CompetitionsList {
@@ -130,8 +192,15 @@ impl KernelsList {
         }
     }
 
+    /// Results per page, kaggle returns at most 100.
     pub fn page_size(mut self, page_size: usize) -> Self {
-        self.page_size = page_size;
+        self.page_size = page_size.clamp(1, 100);
+        self
+    }
+
+    /// The same query for the following page.
+    pub fn next_page(mut self) -> Self {
+        self.page += 1;
         self
     }
 
@@ -186,6 +255,11 @@ impl KernelsList {
     }
 }
 
+/// The query of [`crate::KaggleApiClient::datasets_list`].
+///
+/// The former `size` filter of the api is no longer supported by kaggle,
+/// [`DatasetsList::min_size`] and [`DatasetsList::max_size`] filter by the
+/// size in bytes instead.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DatasetsList {
@@ -237,6 +311,12 @@ impl DatasetsList {
         self
     }
 
+    /// The same query for the following page.
+    pub fn next_page(mut self) -> Self {
+        self.page += 1;
+        self
+    }
+
     pub fn max_size(mut self, max_size: usize) -> Self {
         self.max_size = Some(max_size);
         self
@@ -324,6 +404,52 @@ impl KernelPullRequest {
     }
 }
 
+/// A submission to a code competition, the output file of a kernel version
+/// instead of an uploaded prediction file.
+///
+/// Competitions that expect additional files along with the kernel, like a
+/// description of the solution, get them `attach`ed, these are uploaded
+/// before the submission is made.
+#[derive(Debug, Clone)]
+pub struct CodeSubmission {
+    /// The kernel as `owner/kernel-slug`
+    pub kernel: String,
+    /// The kernel version to submit, the newest if `None`
+    pub kernel_version: Option<u32>,
+    /// The output file of the kernel to submit, defaults to `submission.csv`
+    pub file_name: String,
+    pub description: String,
+    /// Additional files to upload with the submission
+    pub files: Vec<PathBuf>,
+}
+
+impl CodeSubmission {
+    pub fn new(kernel: impl ToString, description: impl ToString) -> Self {
+        Self {
+            kernel: kernel.to_string(),
+            kernel_version: None,
+            file_name: "submission.csv".to_string(),
+            description: description.to_string(),
+            files: Vec::new(),
+        }
+    }
+
+    pub fn kernel_version(mut self, kernel_version: u32) -> Self {
+        self.kernel_version = Some(kernel_version);
+        self
+    }
+
+    pub fn file_name(mut self, file_name: impl ToString) -> Self {
+        self.file_name = file_name.to_string();
+        self
+    }
+
+    pub fn attach(mut self, file: impl AsRef<Path>) -> Self {
+        self.files.push(file.as_ref().to_path_buf());
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,4 +464,100 @@ mod tests {
         let x = Dummy { group: None };
         assert_eq!(r#"{"group":""}"#, serde_json::to_string(&x).unwrap());
     }
+
+    fn kernels_query(list: &KernelsList) -> String {
+        reqwest::Client::new()
+            .get("https://www.kaggle.com/api/v1/kernels/list")
+            .query(list)
+            .build()
+            .unwrap()
+            .url()
+            .query()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn ser_kernels_list_language() {
+        let query = kernels_query(&KernelsList::default().language(Language::Python));
+        assert!(query.contains("language=python"));
+        let query = kernels_query(&KernelsList::default().language(Language::R));
+        assert!(query.contains("language=r&"));
+        let query =
+            kernels_query(&KernelsList::default().language(Language::Other("scala".into())));
+        assert!(query.contains("language=scala"));
+    }
+
+    #[test]
+    fn ser_kernels_list_kernel_type() {
+        let query = kernels_query(&KernelsList::default().kernel_type(KernelType::Script));
+        assert!(query.contains("kernelType=script"));
+        let query = kernels_query(&KernelsList::default().kernel_type(KernelType::Notebook));
+        assert!(query.contains("kernelType=notebook"));
+    }
+
+    #[test]
+    fn ser_kernels_list_output_type() {
+        let query = kernels_query(&KernelsList::default());
+        assert!(query.contains("outputType=all"));
+        let query = kernels_query(&KernelsList::default().output_type(OutputType::Visualization));
+        assert!(query.contains("outputType=visualization"));
+        let query = kernels_query(&KernelsList::default().output_type(OutputType::Data));
+        assert!(query.contains("outputType=data"));
+    }
+
+    #[test]
+    fn kernels_list_page_size() {
+        let query = kernels_query(&KernelsList::default().page_size(500));
+        assert!(query.contains("pageSize=100"));
+        let query = kernels_query(&KernelsList::default().page_size(0));
+        assert!(query.contains("pageSize=1&"));
+    }
+
+    #[test]
+    fn ser_kernels_list_sort_by() {
+        let query = kernels_query(&KernelsList::default());
+        assert!(query.ends_with("sortBy=hotness"));
+        let query = kernels_query(&KernelsList::default().sort_by(SortBy::DateRun));
+        assert!(query.ends_with("sortBy=dateRun"));
+        let query = kernels_query(&KernelsList::default().sort_by(SortBy::Other("custom".into())));
+        assert!(query.ends_with("sortBy=custom"));
+    }
+
+    #[test]
+    fn validate_competition_category() {
+        let categories = vec!["Featured".to_string(), "Getting Started".to_string()];
+        CompetitionsList::default()
+            .category(CompetitionCategory::Masters)
+            .validate_category(&categories)
+            .unwrap();
+        CompetitionsList::default()
+            .category(CompetitionCategory::Other("gettingStarted".to_string()))
+            .validate_category(&categories)
+            .unwrap();
+        assert!(CompetitionsList::default()
+            .category(CompetitionCategory::Other("unknown".to_string()))
+            .validate_category(&categories)
+            .is_err());
+    }
+
+    #[test]
+    fn validate_competition_group() {
+        let groups = vec!["general".to_string(), "inClass".to_string()];
+        CompetitionsList::default()
+            .group(CompetitionGroup::Entered)
+            .validate_group(&groups)
+            .unwrap();
+        CompetitionsList::default()
+            .group(CompetitionGroup::Other("In Class".to_string()))
+            .validate_group(&groups)
+            .unwrap();
+        let err = CompetitionsList::default()
+            .group(CompetitionGroup::Other("unknown".to_string()))
+            .validate_group(&groups)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown competition group `unknown`"));
+    }
 }